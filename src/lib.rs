@@ -1,16 +1,69 @@
-#[cfg(feature = "linked_list_slot_map")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod storage;
+pub use storage::{ArrayFamily, ArrayStorage, Storage, StorageFamily, VecFamily};
+
+// `LinkedListSlotMap` is always backed by `VecFamily`, so it needs an allocator.
+#[cfg(all(feature = "linked_list_slot_map", feature = "alloc"))]
 mod linked_list_slot_map;
-#[cfg(feature = "linked_list_slot_map")]
+#[cfg(all(feature = "linked_list_slot_map", feature = "alloc"))]
 pub use linked_list_slot_map::*;
 
+#[cfg(feature = "secondary_slot_map")]
+mod secondary_slot_map;
+#[cfg(feature = "secondary_slot_map")]
+pub use secondary_slot_map::*;
+
+// `PrioritySlotMap` is always backed by `VecFamily`, so it needs an allocator.
+#[cfg(all(feature = "priority_slot_map", feature = "alloc"))]
+mod priority_slot_map;
+#[cfg(all(feature = "priority_slot_map", feature = "alloc"))]
+pub use priority_slot_map::*;
+
 /// A data structure designed to efficiently store data with persistent IDs.
+///
+/// The `F` type parameter selects the backing [`StorageFamily`] for its
+/// internal buffers: it defaults to [`VecFamily`] (a heap-allocated, growable
+/// `Vec`), or can be [`ArrayFamily`] for fixed-capacity, allocator-free
+/// storage under `no_std`. Without the `alloc` feature there is no default,
+/// since `VecFamily` needs an allocator: write `SlotMap<T, ArrayFamily<N>>`.
+#[cfg(feature = "alloc")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone)]
-pub struct SlotMap<T> {
-    items: Vec<T>,
-    item_to_indirection_index: Vec<usize>,
-    indirection_indices: Vec<Entry>,
-    free_indirection_indices: Vec<usize>,
+pub struct SlotMap<T, F: StorageFamily = VecFamily> {
+    items: F::Storage<T>,
+    item_to_indirection_index: F::Storage<usize>,
+    indirection_indices: F::Storage<Entry>,
+    free_indirection_indices: F::Storage<usize>,
+}
+
+/// See the `alloc`-enabled [`SlotMap`] above; this is the same type, just
+/// without a default for `F` since [`VecFamily`] is unavailable here.
+#[cfg(not(feature = "alloc"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SlotMap<T, F: StorageFamily> {
+    items: F::Storage<T>,
+    item_to_indirection_index: F::Storage<usize>,
+    indirection_indices: F::Storage<Entry>,
+    free_indirection_indices: F::Storage<usize>,
+}
+
+impl<T: Clone, F: StorageFamily> Clone for SlotMap<T, F>
+where
+    F::Storage<T>: Clone,
+    F::Storage<usize>: Clone,
+    F::Storage<Entry>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            item_to_indirection_index: self.item_to_indirection_index.clone(),
+            indirection_indices: self.indirection_indices.clone(),
+            free_indirection_indices: self.free_indirection_indices.clone(),
+        }
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -24,7 +77,7 @@ struct Entry {
 pub struct SlotMapHandle<T> {
     indirection_index: usize,
     generation: usize,
-    phantom: std::marker::PhantomData<fn() -> T>,
+    phantom: core::marker::PhantomData<fn() -> T>,
 }
 
 impl<T> SlotMapHandle<T> {
@@ -32,7 +85,7 @@ impl<T> SlotMapHandle<T> {
         Self {
             indirection_index: index,
             generation,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
@@ -41,8 +94,8 @@ impl<T> SlotMapHandle<T> {
     }
 }
 
-impl<T> std::hash::Hash for SlotMapHandle<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<T> core::hash::Hash for SlotMapHandle<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.indirection_index.hash(state);
         self.generation.hash(state);
     }
@@ -57,13 +110,13 @@ impl<T> PartialEq for SlotMapHandle<T> {
 impl<T> Eq for SlotMapHandle<T> {}
 
 impl<T> PartialOrd for SlotMapHandle<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl<T> Ord for SlotMapHandle<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.indirection_index.cmp(&other.indirection_index)
     }
 }
@@ -72,16 +125,12 @@ impl<T> Copy for SlotMapHandle<T> {}
 
 impl<T> Clone for SlotMapHandle<T> {
     fn clone(&self) -> Self {
-        Self {
-            indirection_index: self.indirection_index,
-            generation: self.generation,
-            phantom: self.phantom,
-        }
+        *self
     }
 }
 
 impl<T> core::fmt::Debug for SlotMapHandle<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SlotMapHandle")
             .field("indirection_index", &self.indirection_index)
             .field("generation", &self.generation)
@@ -93,13 +142,19 @@ impl<T> core::fmt::Debug for SlotMapHandle<T> {
 unsafe impl<T> Send for SlotMapHandle<T> {}
 unsafe impl<T> Sync for SlotMapHandle<T> {}
 
-impl<T> SlotMap<T> {
+impl<T, F: StorageFamily> Default for SlotMap<T, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, F: StorageFamily> SlotMap<T, F> {
     pub fn new() -> Self {
         Self {
-            items: Vec::new(),
-            indirection_indices: Vec::new(),
-            item_to_indirection_index: Vec::new(),
-            free_indirection_indices: Vec::new(),
+            items: F::Storage::<T>::empty(),
+            indirection_indices: F::Storage::<Entry>::empty(),
+            item_to_indirection_index: F::Storage::<usize>::empty(),
+            free_indirection_indices: F::Storage::<usize>::empty(),
         }
     }
 
@@ -107,6 +162,10 @@ impl<T> SlotMap<T> {
         self.items.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.items.iter()
     }
@@ -117,29 +176,31 @@ impl<T> SlotMap<T> {
 
     pub fn iter_with_handle(&self) -> impl Iterator<Item = (&T, SlotMapHandle<T>)> {
         self.items.iter().enumerate().map(|(index, item)| {
-            let indirection_index = self.item_to_indirection_index[index];
-            let generation = self.indirection_indices[indirection_index].generation;
+            let indirection_index = *self.item_to_indirection_index.get(index).unwrap();
+            let generation = self.indirection_indices.get(indirection_index).unwrap().generation;
             (
                 item,
                 SlotMapHandle {
                     indirection_index,
                     generation,
-                    phantom: std::marker::PhantomData,
+                    phantom: core::marker::PhantomData,
                 },
             )
         })
     }
 
     pub fn iter_mut_with_handle(&mut self) -> impl Iterator<Item = (&mut T, SlotMapHandle<T>)> {
-        self.items.iter_mut().enumerate().map(|(index, item)| {
-            let indirection_index = self.item_to_indirection_index[index];
-            let generation = self.indirection_indices[indirection_index].generation;
+        let indirection_indices = &self.indirection_indices;
+        let item_to_indirection_index = &self.item_to_indirection_index;
+        self.items.iter_mut().enumerate().map(move |(index, item)| {
+            let indirection_index = *item_to_indirection_index.get(index).unwrap();
+            let generation = indirection_indices.get(indirection_index).unwrap().generation;
             (
                 item,
                 SlotMapHandle {
                     indirection_index,
                     generation,
-                    phantom: std::marker::PhantomData,
+                    phantom: core::marker::PhantomData,
                 },
             )
         })
@@ -148,7 +209,7 @@ impl<T> SlotMap<T> {
     pub fn next_handle(&self) -> SlotMapHandle<T> {
         let (indirection_index, generation) =
             if let Some(indirection_index) = self.free_indirection_indices.last() {
-                let slot = &self.indirection_indices[*indirection_index];
+                let slot = self.indirection_indices.get(*indirection_index).unwrap();
                 let generation = slot.generation + 1;
 
                 (*indirection_index, generation)
@@ -159,14 +220,14 @@ impl<T> SlotMap<T> {
         SlotMapHandle {
             indirection_index,
             generation,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
     fn new_handle_with_index(&mut self, item_index: usize) -> SlotMapHandle<T> {
         let (indirection_index, generation) =
             if let Some(indirection_index) = self.free_indirection_indices.pop() {
-                let slot = &mut self.indirection_indices[indirection_index];
+                let slot = self.indirection_indices.get_mut(indirection_index).unwrap();
                 let generation = slot.generation + 1;
                 *slot = Entry {
                     item_index,
@@ -175,25 +236,38 @@ impl<T> SlotMap<T> {
                 (indirection_index, generation)
             } else {
                 let indirection_index = self.indirection_indices.len();
-                self.indirection_indices.push(Entry {
-                    item_index,
-                    generation: 0,
-                });
+                self.indirection_indices
+                    .push(Entry {
+                        item_index,
+                        generation: 0,
+                    })
+                    .unwrap_or_else(|_| {
+                        unreachable!("indirection storage capacity must match item storage capacity")
+                    });
                 (indirection_index, 0)
             };
-        self.item_to_indirection_index.push(indirection_index);
+        self.item_to_indirection_index
+            .push(indirection_index)
+            .unwrap_or_else(|_| {
+                unreachable!("indirection storage capacity must match item storage capacity")
+            });
 
         SlotMapHandle {
             indirection_index,
             generation,
-            phantom: std::marker::PhantomData,
+            phantom: core::marker::PhantomData,
         }
     }
 
-    pub fn push(&mut self, item: T) -> SlotMapHandle<T> {
+    /// Inserts `item`, returning its handle.
+    ///
+    /// Returns `item` back unchanged if the backing storage is already at
+    /// capacity (only possible with a fixed-capacity [`StorageFamily`] like
+    /// [`ArrayFamily`]).
+    pub fn push(&mut self, item: T) -> Result<SlotMapHandle<T>, T> {
         let item_index = self.items.len();
-        self.items.push(item);
-        self.new_handle_with_index(item_index)
+        self.items.push(item)?;
+        Ok(self.new_handle_with_index(item_index))
     }
 
     pub fn remove(&mut self, handle: SlotMapHandle<T>) -> Option<T> {
@@ -207,14 +281,39 @@ impl<T> SlotMap<T> {
         item_entry.generation += 1;
 
         let item_index = item_entry.item_index;
-        self.indirection_indices[*self.item_to_indirection_index.last().unwrap()].item_index =
-            item_index;
+        let last_indirection_index = *self.item_to_indirection_index.last().unwrap();
+        self.indirection_indices
+            .get_mut(last_indirection_index)
+            .unwrap()
+            .item_index = item_index;
         let removed_item = self.items.swap_remove(item_index);
         self.item_to_indirection_index.swap_remove(item_index);
-        self.free_indirection_indices.push(handle.indirection_index);
+        self.free_indirection_indices
+            .push(handle.indirection_index)
+            .unwrap_or_else(|_| {
+                unreachable!("indirection storage capacity must match item storage capacity")
+            });
         Some(removed_item)
     }
 
+    /// Removes the item at `handle` after letting `f` mutate it in place.
+    ///
+    /// Unlike [`remove`](Self::remove), the removed value is never handed
+    /// back to the caller, which matters for types that own expensive
+    /// resources (e.g. a buffer) the caller wants to recycle rather than see
+    /// dropped: `f` can `mem::take`/`mem::replace` those resources out of the
+    /// item before it is discarded. Returns `false` if `handle` is stale.
+    pub fn remove_in_place(&mut self, handle: SlotMapHandle<T>, f: impl FnOnce(&mut T)) -> bool {
+        match self.get_mut(handle) {
+            Some(item) => {
+                f(item);
+                self.remove(handle);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn remove_unchecked_generation(&mut self, handle: SlotMapHandle<T>) -> Option<T> {
         let item_entry = self.indirection_indices.get_mut(handle.indirection_index)?;
 
@@ -222,16 +321,23 @@ impl<T> SlotMap<T> {
         item_entry.generation += 1;
 
         let item_index = item_entry.item_index;
-        self.indirection_indices[*self.item_to_indirection_index.last().unwrap()].item_index =
-            item_index;
+        let last_indirection_index = *self.item_to_indirection_index.last().unwrap();
+        self.indirection_indices
+            .get_mut(last_indirection_index)
+            .unwrap()
+            .item_index = item_index;
         let removed_item = self.items.swap_remove(item_index);
         self.item_to_indirection_index.swap_remove(item_index);
-        self.free_indirection_indices.push(handle.indirection_index);
+        self.free_indirection_indices
+            .push(handle.indirection_index)
+            .unwrap_or_else(|_| {
+                unreachable!("indirection storage capacity must match item storage capacity")
+            });
         Some(removed_item)
     }
 
     pub fn get(&self, handle: SlotMapHandle<T>) -> Option<&T> {
-        let entry = &self.indirection_indices[handle.indirection_index];
+        let entry = self.indirection_indices.get(handle.indirection_index)?;
         if entry.generation != handle.generation {
             return None;
         }
@@ -239,43 +345,244 @@ impl<T> SlotMap<T> {
     }
 
     pub fn get_mut(&mut self, handle: SlotMapHandle<T>) -> Option<&mut T> {
-        let entry = &self.indirection_indices[handle.indirection_index];
+        let entry = self.indirection_indices.get(handle.indirection_index)?;
         if entry.generation != handle.generation {
             return None;
         }
-        self.items.get_mut(entry.item_index)
+        let item_index = entry.item_index;
+        self.items.get_mut(item_index)
     }
 
-    /// Mutably access two separate handles.
-    /// Returns (None, None) if handles overlap.
-    pub fn get_mut_twice(
+    /// Mutably access `N` separate handles at once.
+    ///
+    /// Returns `None` if any handle is stale, or if two handles resolve to
+    /// the same live item (aliasing mutable references is never handed out).
+    pub fn get_disjoint_mut<const N: usize>(
         &mut self,
-        handle0: SlotMapHandle<T>,
-        handle1: SlotMapHandle<T>,
-    ) -> (Option<&mut T>, Option<&mut T>) {
-        let entry0 = &self.indirection_indices[handle0.indirection_index];
-        let entry1 = &self.indirection_indices[handle1.indirection_index];
-
-        match entry0.item_index.cmp(&entry1.item_index) {
-            std::cmp::Ordering::Less => {
-                let (v0, v1) = self.items.split_at_mut(entry1.item_index);
-                (v0.get_mut(entry0.item_index), v1.get_mut(0))
-            }
-            std::cmp::Ordering::Greater => {
-                let (v0, v1) = self.items.split_at_mut(entry0.item_index);
-                (v1.get_mut(0), v0.get_mut(entry1.item_index))
+        handles: [SlotMapHandle<T>; N],
+    ) -> Option<[&mut T; N]> {
+        let mut item_indices = [0usize; N];
+        for (slot, handle) in item_indices.iter_mut().zip(handles) {
+            let entry = self.indirection_indices.get(handle.indirection_index)?;
+            if entry.generation != handle.generation {
+                return None;
             }
-            std::cmp::Ordering::Equal => return (None, None),
+            *slot = entry.item_index;
+        }
+
+        let mut sorted_item_indices = item_indices;
+        sorted_item_indices.sort_unstable();
+        if sorted_item_indices.windows(2).any(|pair| pair[0] == pair[1]) {
+            return None;
+        }
+
+        // Visit the handles' item indices in ascending order, carving the
+        // items slice into disjoint pieces with `split_at_mut`, then place
+        // each piece back at the caller's original argument position.
+        let mut argument_order: [usize; N] = core::array::from_fn(|i| i);
+        argument_order.sort_unstable_by_key(|&argument_index| item_indices[argument_index]);
+
+        let mut items: [Option<&mut T>; N] = core::array::from_fn(|_| None);
+        let mut remaining = self.items.as_mut_slice();
+        let mut consumed = 0;
+        for argument_index in argument_order {
+            let item_index = item_indices[argument_index];
+            let (_, rest) = remaining.split_at_mut(item_index - consumed);
+            let (item, rest) = rest.split_first_mut().unwrap();
+            items[argument_index] = Some(item);
+            remaining = rest;
+            consumed = item_index + 1;
         }
+
+        Some(items.map(|item| item.unwrap()))
     }
 
     pub fn get_unchecked_generation(&self, handle: SlotMapHandle<T>) -> Option<&T> {
-        let entry = &self.indirection_indices[handle.indirection_index];
+        let entry = self.indirection_indices.get(handle.indirection_index)?;
         self.items.get(entry.item_index)
     }
 
     pub fn get_mut_unchecked_generation(&mut self, handle: SlotMapHandle<T>) -> Option<&mut T> {
-        let entry = &self.indirection_indices[handle.indirection_index];
-        self.items.get_mut(entry.item_index)
+        let entry = self.indirection_indices.get(handle.indirection_index)?;
+        let item_index = entry.item_index;
+        self.items.get_mut(item_index)
+    }
+
+    /// Removes every element, recycling their indirection slots with bumped
+    /// generations, and returns an iterator over the removed `(value, handle)`
+    /// pairs. Dropping the iterator before exhausting it removes the rest.
+    pub fn drain(&mut self) -> Drain<'_, T, F> {
+        Drain { slot_map: self }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving the
+    /// swap-remove invariants of the backing storage.
+    pub fn retain(&mut self, mut f: impl FnMut(SlotMapHandle<T>, &mut T) -> bool) {
+        let mut index = 0;
+        while index < self.items.len() {
+            let indirection_index = *self.item_to_indirection_index.get(index).unwrap();
+            let generation = self.indirection_indices.get(indirection_index).unwrap().generation;
+            let handle = SlotMapHandle {
+                indirection_index,
+                generation,
+                phantom: core::marker::PhantomData,
+            };
+            if f(handle, self.items.get_mut(index).unwrap()) {
+                index += 1;
+            } else {
+                // `remove` swap-removes, moving the last item into `index`,
+                // so re-examine `index` instead of advancing past it.
+                self.remove(handle);
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`SlotMap::drain`].
+pub struct Drain<'a, T, F: StorageFamily> {
+    slot_map: &'a mut SlotMap<T, F>,
+}
+
+impl<'a, T, F: StorageFamily> Iterator for Drain<'a, T, F> {
+    type Item = (T, SlotMapHandle<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last_item_index = self.slot_map.items.len().checked_sub(1)?;
+        let indirection_index = *self
+            .slot_map
+            .item_to_indirection_index
+            .get(last_item_index)
+            .unwrap();
+        let generation = self
+            .slot_map
+            .indirection_indices
+            .get(indirection_index)
+            .unwrap()
+            .generation;
+        let handle = SlotMapHandle {
+            indirection_index,
+            generation,
+            phantom: core::marker::PhantomData,
+        };
+        let value = self.slot_map.remove(handle).unwrap();
+        Some((value, handle))
+    }
+}
+
+impl<'a, T, F: StorageFamily> Drop for Drain<'a, T, F> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+/// Owning iterator returned by [`SlotMap::into_iter`].
+pub struct IntoIter<T, F: StorageFamily> {
+    slot_map: SlotMap<T, F>,
+}
+
+impl<T, F: StorageFamily> Iterator for IntoIter<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slot_map.items.pop()
+    }
+}
+
+impl<T, F: StorageFamily> IntoIterator for SlotMap<T, F> {
+    type Item = T;
+    type IntoIter = IntoIter<T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { slot_map: self }
+    }
+}
+
+impl<T: Default, F: StorageFamily> SlotMap<T, F> {
+    /// Reserves a slot and returns its handle alongside a mutable reference
+    /// to initialize in place, so the caller doesn't have to build a `T` by
+    /// hand before calling [`push`](Self::push).
+    ///
+    /// The slot starts out holding `T::default()`; overwrite it through the
+    /// returned reference. Returns `None` if the backing storage is already
+    /// at capacity (only possible with a fixed-capacity [`StorageFamily`]
+    /// like [`ArrayFamily`]).
+    pub fn reserve(&mut self) -> Option<(SlotMapHandle<T>, &mut T)> {
+        let handle = self.push(T::default()).ok()?;
+        Some((handle, self.get_mut(handle).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_disjoint_mut_rejects_aliasing_and_stale_handles() {
+        let mut map = SlotMap::<u32, ArrayFamily<4>>::new();
+        let a = map.push(1).unwrap();
+        let b = map.push(2).unwrap();
+        let c = map.push(3).unwrap();
+        map.remove(c);
+
+        let [x, y] = map.get_disjoint_mut([a, b]).unwrap();
+        *x += 10;
+        *y += 20;
+        assert_eq!(map.get(a), Some(&11));
+        assert_eq!(map.get(b), Some(&22));
+
+        assert!(map.get_disjoint_mut([a, a]).is_none());
+        assert!(map.get_disjoint_mut([a, c]).is_none());
+    }
+
+    #[test]
+    fn retain_keeps_matching_elements_despite_swap_remove() {
+        let mut map = SlotMap::<u32, ArrayFamily<8>>::new();
+        for value in 0..6 {
+            map.push(value).unwrap();
+        }
+        map.retain(|_, value| *value % 2 == 0);
+
+        let mut remaining: [u32; 3] = core::array::from_fn(|_| 0);
+        for (slot, (value, _)) in remaining.iter_mut().zip(map.iter_with_handle()) {
+            *slot = *value;
+        }
+        remaining.sort_unstable();
+        assert_eq!(remaining, [0, 2, 4]);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn drain_removes_every_element_and_recycles_the_map() {
+        let mut map = SlotMap::<u32, ArrayFamily<4>>::new();
+        map.push(1).unwrap();
+        map.push(2).unwrap();
+
+        let mut drained: [u32; 2] = core::array::from_fn(|_| 0);
+        for (slot, (value, _)) in drained.iter_mut().zip(map.drain()) {
+            *slot = value;
+        }
+        drained.sort_unstable();
+        assert_eq!(drained, [1, 2]);
+        assert_eq!(map.len(), 0);
+
+        let handle = map.push(3).unwrap();
+        assert_eq!(map.get(handle), Some(&3));
+    }
+
+    #[test]
+    fn reserve_and_remove_in_place_share_push_and_remove_paths() {
+        let mut map = SlotMap::<u32, ArrayFamily<2>>::new();
+        let (handle, slot) = map.reserve().unwrap();
+        *slot = 42;
+        assert_eq!(map.get(handle), Some(&42));
+
+        let mut seen = 0;
+        assert!(map.remove_in_place(handle, |value| seen = *value));
+        assert_eq!(seen, 42);
+        assert_eq!(map.get(handle), None);
+
+        map.push(1).unwrap();
+        map.push(2).unwrap();
+        assert!(map.reserve().is_none());
     }
 }
@@ -1,3 +1,5 @@
+extern crate alloc;
+
 use crate::*;
 
 /// A data structure that can accomodate multiple linked-lists stored within it.
@@ -14,7 +16,7 @@ impl<T> LinkedListSlotMapHandle<T> {
 }
 impl<T> Clone for LinkedListSlotMapHandle<T> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        *self
     }
 }
 
@@ -28,6 +30,12 @@ impl<T> PartialEq for LinkedListSlotMapHandle<T> {
 
 impl<T> Eq for LinkedListSlotMapHandle<T> {}
 
+impl<T> Default for LinkedListSlotMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> LinkedListSlotMap<T> {
     pub fn new() -> Self {
         Self {
@@ -41,11 +49,14 @@ impl<T> LinkedListSlotMap<T> {
         value: T,
     ) -> LinkedListSlotMapHandle<T> {
         let next = previous.and_then(|p| self.slot_map.get(p.0).unwrap().next);
-        let new_handle = self.slot_map.push(Node {
-            value,
-            next,
-            previous: previous.map(|p| p.0),
-        });
+        let new_handle = self
+            .slot_map
+            .push(Node {
+                value,
+                next,
+                previous: previous.map(|p| p.0),
+            })
+            .unwrap_or_else(|_| unreachable!("LinkedListSlotMap uses the default, growable VecFamily"));
 
         if let Some(previous) = previous {
             self.slot_map.get_mut(previous.0).unwrap().next = Some(new_handle);
@@ -79,7 +90,7 @@ impl<T> LinkedListSlotMap<T> {
         )
     }
 
-    pub fn iter(&self, start_node: LinkedListSlotMapHandle<T>) -> LinkedListSlotMapIterator<T> {
+    pub fn iter(&self, start_node: LinkedListSlotMapHandle<T>) -> LinkedListSlotMapIterator<'_, T> {
         LinkedListSlotMapIterator {
             linked_list_slot_map: self,
             current_node: Some(start_node.0),
@@ -113,7 +124,7 @@ impl<T> LinkedListSlotMap<T> {
     pub fn reverse_iter(
         &self,
         start_node: LinkedListSlotMapHandle<T>,
-    ) -> RevLinkedListSlotMapIterator<T> {
+    ) -> RevLinkedListSlotMapIterator<'_, T> {
         RevLinkedListSlotMapIterator {
             linked_list_slot_map: self,
             current_node: Some(start_node.0),
@@ -124,12 +135,21 @@ impl<T> LinkedListSlotMap<T> {
     pub fn reverse_remove_iter(
         &mut self,
         start_node: LinkedListSlotMapHandle<T>,
-    ) -> RevRemoveLinkedListSlotMapIterator<T> {
+    ) -> RevRemoveLinkedListSlotMapIterator<'_, T> {
         RevRemoveLinkedListSlotMapIterator {
             linked_list_slot_map: self,
             current_node: Some(start_node.0),
         }
     }
+
+    /// Returns a cursor over this chain, starting at `start_node`.
+    pub fn cursor_mut(&mut self, start_node: LinkedListSlotMapHandle<T>) -> CursorMut<'_, T> {
+        CursorMut {
+            linked_list_slot_map: self,
+            current_node: Some(start_node.0),
+            ghost_boundary: None,
+        }
+    }
 }
 
 pub struct LinkedListSlotMapIterator<'a, T> {
@@ -180,8 +200,302 @@ impl<'a, T> Iterator for RevRemoveLinkedListSlotMapIterator<'a, T> {
     }
 }
 
+/// One end of a chain: either where a [`CursorMut`] left it to reach the
+/// ghost position, or which end a ghost-position insert should target.
+#[derive(Clone, Copy)]
+enum Boundary {
+    Head,
+    Tail,
+}
+
+/// A cursor over one chain within a [`LinkedListSlotMap`], giving O(1)
+/// positional edits without the caller threading handles manually. `None`
+/// is the "ghost" position one past either end of the chain.
+pub struct CursorMut<'a, T> {
+    linked_list_slot_map: &'a mut LinkedListSlotMap<T>,
+    current_node: Option<SlotMapHandle<Node<T>>>,
+    // Which real node and end the cursor left to reach the ghost position,
+    // so a ghost-position insert can splice in rather than orphan.
+    ghost_boundary: Option<(SlotMapHandle<Node<T>>, Boundary)>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn current(&self) -> Option<&T> {
+        let handle = self.current_node?;
+        self.linked_list_slot_map
+            .slot_map
+            .get(handle)
+            .map(|node| &node.value)
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let handle = self.current_node?;
+        self.linked_list_slot_map
+            .slot_map
+            .get_mut(handle)
+            .map(|node| &mut node.value)
+    }
+
+    /// Moves to the next node, or to the ghost position if already at the
+    /// end. Moving next from the ghost position left by [`move_prev`](Self::move_prev)
+    /// returns to the node that was there.
+    pub fn move_next(&mut self) {
+        match self.current_node {
+            Some(handle) => {
+                let next = self.linked_list_slot_map.slot_map.get(handle).unwrap().next;
+                if next.is_none() {
+                    self.ghost_boundary = Some((handle, Boundary::Tail));
+                }
+                self.current_node = next;
+            }
+            None => {
+                if let Some((boundary, Boundary::Head)) = self.ghost_boundary {
+                    self.current_node = Some(boundary);
+                    self.ghost_boundary = None;
+                }
+            }
+        }
+    }
+
+    /// Moves to the previous node, or to the ghost position if already at
+    /// the start. Moving previous from the ghost position left by
+    /// [`move_next`](Self::move_next) returns to the node that was there.
+    pub fn move_prev(&mut self) {
+        match self.current_node {
+            Some(handle) => {
+                let previous = self
+                    .linked_list_slot_map
+                    .slot_map
+                    .get(handle)
+                    .unwrap()
+                    .previous;
+                if previous.is_none() {
+                    self.ghost_boundary = Some((handle, Boundary::Head));
+                }
+                self.current_node = previous;
+            }
+            None => {
+                if let Some((boundary, Boundary::Tail)) = self.ghost_boundary {
+                    self.current_node = Some(boundary);
+                    self.ghost_boundary = None;
+                }
+            }
+        }
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        let handle = self.current_node?;
+        let next = self.linked_list_slot_map.slot_map.get(handle).unwrap().next?;
+        Some(&self.linked_list_slot_map.slot_map.get(next).unwrap().value)
+    }
+
+    pub fn peek_prev(&self) -> Option<&T> {
+        let handle = self.current_node?;
+        let previous = self
+            .linked_list_slot_map
+            .slot_map
+            .get(handle)
+            .unwrap()
+            .previous?;
+        Some(
+            &self
+                .linked_list_slot_map
+                .slot_map
+                .get(previous)
+                .unwrap()
+                .value,
+        )
+    }
+
+    /// Inserts `value` immediately before the current node, without moving
+    /// the cursor. At the ghost position this appends onto the tail,
+    /// regardless of which end the cursor left to get there, and the cursor
+    /// moves onto the new node.
+    pub fn insert_before(&mut self, value: T) -> LinkedListSlotMapHandle<T> {
+        let Some(current) = self.current_node else {
+            return self.insert_at_boundary(value, Boundary::Tail);
+        };
+        let previous = self.linked_list_slot_map.slot_map.get(current).unwrap().previous;
+        let new_handle = self
+            .linked_list_slot_map
+            .slot_map
+            .push(Node {
+                value,
+                next: Some(current),
+                previous,
+            })
+            .unwrap_or_else(|_| unreachable!("LinkedListSlotMap uses the default, growable VecFamily"));
+
+        self.linked_list_slot_map.slot_map.get_mut(current).unwrap().previous = Some(new_handle);
+        if let Some(previous) = previous {
+            self.linked_list_slot_map.slot_map.get_mut(previous).unwrap().next = Some(new_handle);
+        }
+        LinkedListSlotMapHandle(new_handle)
+    }
+
+    /// Inserts `value` immediately after the current node, without moving
+    /// the cursor. At the ghost position this prepends onto the head,
+    /// regardless of which end the cursor left to get there, and the cursor
+    /// moves onto the new node.
+    pub fn insert_after(&mut self, value: T) -> LinkedListSlotMapHandle<T> {
+        let Some(current) = self.current_node else {
+            return self.insert_at_boundary(value, Boundary::Head);
+        };
+        let next = self.linked_list_slot_map.slot_map.get(current).unwrap().next;
+        let new_handle = self
+            .linked_list_slot_map
+            .slot_map
+            .push(Node {
+                value,
+                next,
+                previous: Some(current),
+            })
+            .unwrap_or_else(|_| unreachable!("LinkedListSlotMap uses the default, growable VecFamily"));
+
+        self.linked_list_slot_map.slot_map.get_mut(current).unwrap().next = Some(new_handle);
+        if let Some(next) = next {
+            self.linked_list_slot_map.slot_map.get_mut(next).unwrap().previous = Some(new_handle);
+        }
+        LinkedListSlotMapHandle(new_handle)
+    }
+
+    /// Walks from `node` to the real tail (`end == Tail`) or head
+    /// (`end == Head`) of its chain, following `next`/`previous` links.
+    fn find_end(&self, mut node: SlotMapHandle<Node<T>>, end: Boundary) -> SlotMapHandle<Node<T>> {
+        loop {
+            let linked = match end {
+                Boundary::Tail => self.linked_list_slot_map.slot_map.get(node).unwrap().next,
+                Boundary::Head => self.linked_list_slot_map.slot_map.get(node).unwrap().previous,
+            };
+            match linked {
+                Some(next) => node = next,
+                None => return node,
+            }
+        }
+    }
+
+    /// Inserts `value` at the ghost position, splicing onto the chain's
+    /// tail (`end == Tail`, for [`insert_before`](Self::insert_before)) or
+    /// head (`end == Head`, for [`insert_after`](Self::insert_after)) —
+    /// independent of which end the cursor left to reach the ghost. With no
+    /// remembered boundary, the node is pushed standalone. Either way the new
+    /// node becomes current.
+    fn insert_at_boundary(&mut self, value: T, end: Boundary) -> LinkedListSlotMapHandle<T> {
+        let anchor = self.ghost_boundary.map(|(anchor, _)| self.find_end(anchor, end));
+        let (next, previous) = match (end, anchor) {
+            (Boundary::Tail, Some(tail)) => (None, Some(tail)),
+            (Boundary::Head, Some(head)) => (Some(head), None),
+            (_, None) => (None, None),
+        };
+        let new_handle = self
+            .linked_list_slot_map
+            .slot_map
+            .push(Node { value, next, previous })
+            .unwrap_or_else(|_| unreachable!("LinkedListSlotMap uses the default, growable VecFamily"));
+
+        if let Some(anchor) = previous {
+            self.linked_list_slot_map.slot_map.get_mut(anchor).unwrap().next = Some(new_handle);
+        }
+        if let Some(anchor) = next {
+            self.linked_list_slot_map.slot_map.get_mut(anchor).unwrap().previous = Some(new_handle);
+        }
+        self.current_node = Some(new_handle);
+        self.ghost_boundary = None;
+        LinkedListSlotMapHandle(new_handle)
+    }
+
+    /// Removes the current node, returning its value and advancing the
+    /// cursor to the node that followed it (or the ghost position).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let handle = self.current_node?;
+        let (value, previous, next) = self.linked_list_slot_map.remove(LinkedListSlotMapHandle(handle));
+        self.current_node = next.map(|handle| handle.0);
+        if self.current_node.is_none() {
+            self.ghost_boundary = previous.map(|handle| (handle.0, Boundary::Tail));
+        }
+        Some(value)
+    }
+}
+
 pub struct Node<T> {
     value: T,
     next: Option<SlotMapHandle<Node<T>>>,
     previous: Option<SlotMapHandle<Node<T>>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn build(list: &mut LinkedListSlotMap<i32>, values: &[i32]) -> LinkedListSlotMapHandle<i32> {
+        let mut previous = None;
+        let mut head = None;
+        for &value in values {
+            let handle = list.insert(previous, value);
+            head.get_or_insert(handle);
+            previous = Some(handle);
+        }
+        head.unwrap()
+    }
+
+    fn collect(list: &LinkedListSlotMap<i32>, head: LinkedListSlotMapHandle<i32>) -> Vec<i32> {
+        list.iter(head).map(|(value, _)| *value).collect()
+    }
+
+    #[test]
+    fn insert_before_at_ghost_via_move_next_appends_onto_tail() {
+        let mut list = LinkedListSlotMap::new();
+        let head = build(&mut list, &[1, 2, 3]);
+
+        let mut cursor = list.cursor_mut(head);
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(4);
+
+        assert_eq!(collect(&list, head), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn insert_after_at_ghost_via_move_next_prepends_onto_head() {
+        let mut list = LinkedListSlotMap::new();
+        let head = build(&mut list, &[1, 2, 3]);
+
+        let mut cursor = list.cursor_mut(head);
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        let new_head = cursor.insert_after(0);
+
+        assert_eq!(collect(&list, new_head), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn insert_before_at_ghost_via_move_prev_still_appends_onto_tail() {
+        let mut list = LinkedListSlotMap::new();
+        let head = build(&mut list, &[1, 2, 3]);
+
+        let mut cursor = list.cursor_mut(head);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(4);
+
+        assert_eq!(collect(&list, head), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn insert_after_at_ghost_via_move_prev_still_prepends_onto_head() {
+        let mut list = LinkedListSlotMap::new();
+        let head = build(&mut list, &[1, 2, 3]);
+
+        let mut cursor = list.cursor_mut(head);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        let new_head = cursor.insert_after(0);
+
+        assert_eq!(collect(&list, new_head), [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,187 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use crate::*;
+
+/// A priority queue built on [`SlotMap`], giving stable handles into a
+/// binary heap ordered by priority, so a handle survives
+/// [`change_priority`](Self::change_priority) (decrease-key/increase-key).
+pub struct PrioritySlotMap<T, P: Ord> {
+    entries: SlotMap<PriorityEntry<T, P>>,
+    // Handles into `entries` rather than raw indices, so this stays correct
+    // across `entries`'s own swap-removes.
+    heap: Vec<PrioritySlotMapHandle<T, P>>,
+}
+
+pub struct PriorityEntry<T, P> {
+    value: T,
+    priority: P,
+    // This entry's current position in `PrioritySlotMap::heap`, kept in
+    // sync by every swap.
+    heap_index: usize,
+}
+
+pub struct PrioritySlotMapHandle<T, P>(pub(crate) SlotMapHandle<PriorityEntry<T, P>>);
+
+impl<T, P> PrioritySlotMapHandle<T, P> {
+    pub fn inner_handle(&self) -> SlotMapHandle<PriorityEntry<T, P>> {
+        self.0
+    }
+}
+
+impl<T, P> Clone for PrioritySlotMapHandle<T, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, P> Copy for PrioritySlotMapHandle<T, P> {}
+
+impl<T, P> PartialEq for PrioritySlotMapHandle<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, P> Eq for PrioritySlotMapHandle<T, P> {}
+
+impl<T, P: Ord> Default for PrioritySlotMap<T, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P: Ord> PrioritySlotMap<T, P> {
+    pub fn new() -> Self {
+        Self {
+            entries: SlotMap::new(),
+            heap: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the highest-priority item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        let handle = *self.heap.first()?;
+        Some(&self.entries.get(handle.0).unwrap().value)
+    }
+
+    /// Inserts `value` with `priority`, sifting it up into place.
+    pub fn push(&mut self, value: T, priority: P) -> PrioritySlotMapHandle<T, P> {
+        let heap_index = self.heap.len();
+        let handle = PrioritySlotMapHandle(
+            self.entries
+                .push(PriorityEntry {
+                    value,
+                    priority,
+                    heap_index,
+                })
+                .unwrap_or_else(|_| unreachable!("PrioritySlotMap uses the default, growable VecFamily")),
+        );
+        self.heap.push(handle);
+        self.sift_up(heap_index);
+        handle
+    }
+
+    /// Removes and returns the highest-priority item.
+    pub fn pop(&mut self) -> Option<T> {
+        let handle = *self.heap.first()?;
+        let last = self.heap.len() - 1;
+        self.swap_heap(0, last);
+        self.heap.pop();
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        self.entries.remove(handle.0).map(|entry| entry.value)
+    }
+
+    /// Changes `handle`'s priority and re-sifts it into place.
+    pub fn change_priority(&mut self, handle: PrioritySlotMapHandle<T, P>, new_priority: P) {
+        let Some(entry) = self.entries.get_mut(handle.0) else {
+            return;
+        };
+        let heap_index = entry.heap_index;
+        entry.priority = new_priority;
+        self.sift_up(heap_index);
+        self.sift_down(heap_index);
+    }
+
+    fn priority_at(&self, heap_index: usize) -> &P {
+        &self.entries.get(self.heap[heap_index].0).unwrap().priority
+    }
+
+    fn swap_heap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.entries.get_mut(self.heap[a].0).unwrap().heap_index = a;
+        self.entries.get_mut(self.heap[b].0).unwrap().heap_index = b;
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.priority_at(index) > self.priority_at(parent) {
+                self.swap_heap(index, parent);
+                index = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = index * 2 + 1;
+            let right = index * 2 + 2;
+            let mut largest = index;
+            if left < self.heap.len() && self.priority_at(left) > self.priority_at(largest) {
+                largest = left;
+            }
+            if right < self.heap.len() && self.priority_at(right) > self.priority_at(largest) {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+            self.swap_heap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_returns_items_in_descending_priority_order() {
+        let mut queue = PrioritySlotMap::new();
+        queue.push("low", 1);
+        queue.push("high", 10);
+        queue.push("mid", 5);
+
+        assert_eq!(queue.pop(), Some("high"));
+        assert_eq!(queue.pop(), Some("mid"));
+        assert_eq!(queue.pop(), Some("low"));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn change_priority_resifts_the_handle_into_place() {
+        let mut queue = PrioritySlotMap::new();
+        let low = queue.push("a", 1);
+        queue.push("b", 5);
+
+        queue.change_priority(low, 10);
+        assert_eq!(queue.peek(), Some(&"a"));
+
+        queue.change_priority(low, 0);
+        assert_eq!(queue.peek(), Some(&"b"));
+    }
+}
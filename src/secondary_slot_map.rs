@@ -0,0 +1,112 @@
+#[cfg(feature = "alloc")]
+use crate::VecFamily;
+use crate::{SlotMapHandle, Storage, StorageFamily};
+use core::marker::PhantomData;
+
+/// Associates extra data `V` with handles minted by a primary `SlotMap<T>`,
+/// without owning any of the primary map's items.
+///
+/// Each slot remembers the generation of the handle it was inserted with, so
+/// a stale handle (one whose slot has since been reused) never reads,
+/// mutates or removes data belonging to the new occupant. `insert` does not
+/// compare generations: it replaces whatever the slot holds.
+#[cfg(feature = "alloc")]
+pub struct SecondarySlotMap<T, V, F: StorageFamily = VecFamily> {
+    slots: F::Storage<Option<(usize, V)>>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+/// See the `alloc`-enabled [`SecondarySlotMap`] above; this is the same type,
+/// just without a default for `F` since `VecFamily` is unavailable here.
+#[cfg(not(feature = "alloc"))]
+pub struct SecondarySlotMap<T, V, F: StorageFamily> {
+    slots: F::Storage<Option<(usize, V)>>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, V, F: StorageFamily> Default for SecondarySlotMap<T, V, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V, F: StorageFamily> SecondarySlotMap<T, V, F> {
+    pub fn new() -> Self {
+        Self {
+            slots: F::Storage::<Option<(usize, V)>>::empty(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Grows `slots` up to and including `indirection_index`, or returns
+    /// `None` if this map's own backing storage is already at capacity.
+    fn slot_for(&mut self, indirection_index: usize) -> Option<&mut Option<(usize, V)>> {
+        while self.slots.len() <= indirection_index {
+            self.slots.push(None).ok()?;
+        }
+        Some(self.slots.get_mut(indirection_index).unwrap())
+    }
+
+    /// Associates `value` with `handle`, returning the previous value stored
+    /// for this slot, if any, or giving `value` back if this map's backing
+    /// storage is already at capacity.
+    pub fn insert(&mut self, handle: SlotMapHandle<T>, value: V) -> Result<Option<V>, V> {
+        let (indirection_index, generation) = handle.index_and_generation();
+        match self.slot_for(indirection_index) {
+            Some(slot) => Ok(slot.replace((generation, value)).map(|(_, v)| v)),
+            None => Err(value),
+        }
+    }
+
+    pub fn get(&self, handle: SlotMapHandle<T>) -> Option<&V> {
+        let (indirection_index, generation) = handle.index_and_generation();
+        match self.slots.get(indirection_index) {
+            Some(Some((slot_generation, value))) if *slot_generation == generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, handle: SlotMapHandle<T>) -> Option<&mut V> {
+        let (indirection_index, generation) = handle.index_and_generation();
+        match self.slots.get_mut(indirection_index) {
+            Some(Some((slot_generation, value))) if *slot_generation == generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Removes and returns the value associated with `handle`, if its
+    /// generation is still current.
+    pub fn remove(&mut self, handle: SlotMapHandle<T>) -> Option<V> {
+        let (indirection_index, generation) = handle.index_and_generation();
+        let slot = self.slots.get_mut(indirection_index)?;
+        match slot {
+            Some((slot_generation, _)) if *slot_generation == generation => {
+                slot.take().map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArrayFamily, SlotMap};
+
+    #[test]
+    fn insert_fails_past_own_capacity_even_if_primary_map_has_room() {
+        let mut primary = SlotMap::<&'static str, ArrayFamily<8>>::new();
+        let mut secondary =
+            super::SecondarySlotMap::<&'static str, u32, ArrayFamily<2>>::new();
+
+        let a = primary.push("a").unwrap();
+        let b = primary.push("b").unwrap();
+        let c = primary.push("c").unwrap();
+
+        assert_eq!(secondary.insert(a, 1), Ok(None));
+        assert_eq!(secondary.insert(b, 2), Ok(None));
+        assert_eq!(secondary.insert(c, 3), Err(3));
+
+        assert_eq!(secondary.get(a), Some(&1));
+        assert_eq!(secondary.get(c), None);
+    }
+}
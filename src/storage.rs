@@ -0,0 +1,220 @@
+//! Pluggable backing storage for [`SlotMap`](crate::SlotMap)'s internal
+//! buffers: [`VecFamily`] by default, or [`ArrayFamily`] for fixed-capacity,
+//! allocator-free storage under `#![no_std]`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::mem::MaybeUninit;
+
+/// A single buffer used internally by [`SlotMap`](crate::SlotMap).
+pub trait Storage<T> {
+    /// Creates an empty buffer.
+    fn empty() -> Self;
+
+    /// The maximum number of elements this buffer can ever hold.
+    fn capacity(&self) -> usize;
+
+    /// Appends `value`, returning it back if the buffer is already at capacity.
+    fn push(&mut self, value: T) -> Result<(), T>;
+
+    /// Removes and returns the last element, if any.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Removes element `index`, replacing it with the last element.
+    ///
+    /// Panics if `index` is out of bounds, matching `Vec::swap_remove`.
+    fn swap_remove(&mut self, index: usize) -> T;
+
+    fn as_slice(&self) -> &[T];
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+/// Selects the concrete [`Storage`] implementation that a
+/// [`SlotMap`](crate::SlotMap) uses for each of its internal buffers.
+pub trait StorageFamily {
+    type Storage<T>: Storage<T>;
+}
+
+/// The default family: every buffer is a heap-allocated, growable `Vec`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VecFamily;
+
+#[cfg(feature = "alloc")]
+impl StorageFamily for VecFamily {
+    type Storage<T> = Vec<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Storage<T> for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    fn push(&mut self, value: T) -> Result<(), T> {
+        Vec::push(self, value);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        Vec::pop(self)
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        Vec::swap_remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// A fixed-capacity family of `N` elements per buffer, requiring no
+/// allocator. Use as `SlotMap<T, ArrayFamily<N>>` under `no_std`.
+pub struct ArrayFamily<const N: usize>;
+
+impl<const N: usize> StorageFamily for ArrayFamily<N> {
+    type Storage<T> = ArrayStorage<T, N>;
+}
+
+/// Fixed-capacity, allocator-free storage backing [`ArrayFamily`].
+pub struct ArrayStorage<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Storage<T> for ArrayStorage<T, N> {
+    fn empty() -> Self {
+        Self {
+            items: core::array::from_fn(|_| MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+        self.items[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.items[self.len].assume_init_read() })
+    }
+
+    fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        self.items.swap(index, self.len - 1);
+        self.len -= 1;
+        unsafe { self.items[self.len].assume_init_read() }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.items.as_ptr() as *const T, self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.items.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayStorage<T, N> {
+    fn drop(&mut self) {
+        for item in &mut self.items[..self.len] {
+            unsafe { item.assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_fails_once_past_capacity_without_losing_existing_elements() {
+        let mut storage = ArrayStorage::<u32, 2>::empty();
+        assert_eq!(storage.push(1), Ok(()));
+        assert_eq!(storage.push(2), Ok(()));
+        assert_eq!(storage.push(3), Err(3));
+        assert_eq!(storage.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn swap_remove_moves_the_last_element_into_the_removed_slot() {
+        let mut storage = ArrayStorage::<u32, 4>::empty();
+        storage.push(1).unwrap();
+        storage.push(2).unwrap();
+        storage.push(3).unwrap();
+
+        assert_eq!(storage.swap_remove(0), 1);
+        assert_eq!(storage.as_slice(), [3, 2]);
+    }
+
+    #[test]
+    fn drop_runs_destructors_only_for_initialized_elements() {
+        use core::cell::Cell;
+
+        struct DropCounter<'a>(&'a Cell<u32>);
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let mut storage = ArrayStorage::<DropCounter, 4>::empty();
+        assert!(storage.push(DropCounter(&drops)).is_ok());
+        assert!(storage.push(DropCounter(&drops)).is_ok());
+        drop(storage);
+
+        assert_eq!(drops.get(), 2);
+    }
+}